@@ -0,0 +1,164 @@
+// --- SELF-PLAY BENCHMARK HARNESS ---
+// Runs the `HangmanSolver` headlessly over a word list and reports
+// aggregate win-rate statistics. Only compiled in when the `bench`
+// cargo feature is enabled, since it pulls in `rayon` and isn't needed
+// for normal interactive play.
+#![cfg(feature = "bench")]
+
+use crate::solver::HangmanSolver;
+use crate::Game;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+
+// --- STRUCT DEFINITION ---
+// Aggregate results from a batch of self-play games.
+#[derive(Debug, Default)]
+pub struct BenchSummary {
+    pub games_played: usize,
+    pub wins: usize,
+    pub wrong_guesses_total: u32,
+    /// Total guesses made (hits and misses) in each won game, i.e. how many
+    /// letters the solver had to try before completing the word.
+    pub guesses_to_win: Vec<u32>,
+}
+
+impl BenchSummary {
+    // --- FUNCTION 1: win_rate ---
+    // Percentage of games the solver won, in the 0.0..=100.0 range.
+    pub fn win_rate(&self) -> f64 {
+        if self.games_played == 0 {
+            return 0.0;
+        }
+        (self.wins as f64 / self.games_played as f64) * 100.0
+    }
+
+    // --- FUNCTION 2: average_wrong_guesses ---
+    // Mean number of wrong guesses across every game played.
+    pub fn average_wrong_guesses(&self) -> f64 {
+        if self.games_played == 0 {
+            return 0.0;
+        }
+        self.wrong_guesses_total as f64 / self.games_played as f64
+    }
+
+    // --- FUNCTION 3: print_table ---
+    // Prints a small summary table to stdout.
+    pub fn print_table(&self) {
+        println!("\n--- Self-Play Benchmark Results ---");
+        println!("Games played:          {}", self.games_played);
+        println!(
+            "Win rate:              {:.1}% ({}/{})",
+            self.win_rate(),
+            self.wins,
+            self.games_played
+        );
+        println!(
+            "Average wrong guesses:  {:.2}",
+            self.average_wrong_guesses()
+        );
+
+        println!("Guesses-to-win distribution:");
+        let mut counts: BTreeMap<u32, usize> = BTreeMap::new();
+        for &total in &self.guesses_to_win {
+            *counts.entry(total).or_default() += 1;
+        }
+        for (total, count) in counts {
+            println!("  {} guesses: {} win(s)", total, count);
+        }
+    }
+}
+
+// --- FUNCTION 4: run_benchmark ---
+// Plays `games` rounds of solver-only self-play against words drawn from
+// `word_list` and returns the aggregated statistics. Games run in parallel
+// via rayon since each one is fully independent.
+pub fn run_benchmark(word_list: &[String], games: usize) -> BenchSummary {
+    let mut rng = thread_rng();
+    let sample: Vec<String> = (0..games)
+        .filter_map(|_| word_list.choose(&mut rng).cloned())
+        .collect();
+
+    let results: Vec<(bool, u8, u32)> = sample
+        .par_iter()
+        .map(|word| simulate_round(word, word_list))
+        .collect();
+
+    let mut summary = BenchSummary {
+        games_played: results.len(),
+        ..Default::default()
+    };
+
+    for (won, wrong_guesses, total_guesses) in results {
+        summary.wrong_guesses_total += wrong_guesses as u32;
+        if won {
+            summary.wins += 1;
+            summary.guesses_to_win.push(total_guesses);
+        }
+    }
+
+    summary
+}
+
+// --- FUNCTION 5: simulate_round ---
+// Plays a single round by driving the same `Game` API the CLI uses, with
+// only the solver's recommendations and no terminal I/O. Returns whether the
+// solver won, how many wrong guesses it made, and the total number of
+// guesses (hits and misses) it took along the way.
+fn simulate_round(secret_word: &str, word_list: &[String]) -> (bool, u8, u32) {
+    let mut game = Game::new(secret_word);
+    let word_length = game.hidden_word_display().len();
+    let mut solver = HangmanSolver::new(word_list, word_length);
+    let mut total_guesses = 0u32;
+
+    loop {
+        if game.is_over() {
+            return (game.is_won(), game.wrong_guesses_count(), total_guesses);
+        }
+
+        let guessed_char = match solver.best_guess(game.guessed_letters()) {
+            Some(letter) => letter,
+            None => return (false, game.wrong_guesses_count(), total_guesses),
+        };
+
+        let outcome = game
+            .guess(guessed_char)
+            .expect("solver only suggests unguessed letters while the game is in progress");
+        total_guesses += 1;
+        solver.record_guess(guessed_char, outcome.hit, game.hidden_word_display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulate_round_wins_with_a_single_candidate_word() {
+        let word_list = vec!["cat".to_string()];
+        let (won, wrong_guesses, total_guesses) = simulate_round("cat", &word_list);
+
+        assert!(won);
+        assert_eq!(wrong_guesses, 0);
+        assert_eq!(total_guesses, 3);
+    }
+
+    #[test]
+    fn win_rate_and_average_wrong_guesses_are_zero_with_no_games() {
+        let summary = BenchSummary::default();
+        assert_eq!(summary.win_rate(), 0.0);
+        assert_eq!(summary.average_wrong_guesses(), 0.0);
+    }
+
+    #[test]
+    fn run_benchmark_reports_wins_for_a_single_candidate_word() {
+        let word_list = vec!["cat".to_string()];
+        let summary = run_benchmark(&word_list, 3);
+
+        assert_eq!(summary.games_played, 3);
+        assert_eq!(summary.wins, 3);
+        assert_eq!(summary.win_rate(), 100.0);
+        assert_eq!(summary.guesses_to_win, vec![3, 3, 3]);
+    }
+}
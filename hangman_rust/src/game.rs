@@ -0,0 +1,292 @@
+// --- CORE GAME STATE ---
+// Holds everything needed to play a single round of Hangman, independent
+// of any particular front end (CLI, WASM, or the solver/bench harnesses).
+use std::fmt;
+
+// --- GLOBAL CONSTANTS ---
+pub const MAX_WRONG_GUESSES: u8 = 6;
+
+// --- ENUM DEFINITION ---
+// Everything that can go wrong when calling `Game::guess` or `Game::undo`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HangmanError {
+    /// The supplied character isn't an ASCII letter.
+    InvalidLetter,
+    /// That letter has already been guessed this round.
+    AlreadyGuessed,
+    /// The round is already over (won or lost); no more guesses accepted.
+    GameOver,
+    /// There is no recorded guess left to undo.
+    NothingToUndo,
+}
+
+impl fmt::Display for HangmanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HangmanError::InvalidLetter => write!(f, "guess must be a single alphabetic letter"),
+            HangmanError::AlreadyGuessed => write!(f, "that letter has already been guessed"),
+            HangmanError::GameOver => write!(f, "the game is already over"),
+            HangmanError::NothingToUndo => write!(f, "there is no guess left to undo"),
+        }
+    }
+}
+
+impl std::error::Error for HangmanError {}
+
+// --- STRUCT DEFINITION ---
+// Reports what happened as a result of a single accepted guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuessOutcome {
+    /// Whether the guessed letter appears in the secret word.
+    pub hit: bool,
+    /// How many positions were revealed by this guess (0 for a miss).
+    pub positions_revealed: usize,
+    /// Whether the round ended (won or lost) as a result of this guess.
+    pub game_over: bool,
+}
+
+// --- STRUCT DEFINITION ---
+// A snapshot of the mutable round state taken right before a guess is
+// applied, so `Game::undo` can restore it exactly.
+#[derive(Clone)]
+struct GameSnapshot {
+    hidden_word_display: Vec<char>,
+    guessed_letters: Vec<char>,
+    wrong_guesses_count: u8,
+}
+
+// --- STRUCT DEFINITION ---
+// Owns the state of one Hangman round. Front ends drive it by calling
+// `guess` and reading back the display/guessed-letter state after each
+// call, which keeps this struct itself free of any I/O.
+pub struct Game {
+    secret_word_chars: Vec<char>,
+    hidden_word_display: Vec<char>,
+    guessed_letters: Vec<char>,
+    wrong_guesses_count: u8,
+    history: Vec<GameSnapshot>,
+}
+
+impl Game {
+    // --- FUNCTION 1: new ---
+    // Starts a fresh round for `secret_word` (case-insensitive).
+    pub fn new(secret_word: &str) -> Self {
+        let secret_word_chars: Vec<char> = secret_word.to_uppercase().chars().collect();
+        let hidden_word_display = vec!['_'; secret_word_chars.len()];
+
+        Game {
+            secret_word_chars,
+            hidden_word_display,
+            guessed_letters: Vec::new(),
+            wrong_guesses_count: 0,
+            history: Vec::new(),
+        }
+    }
+
+    // --- FUNCTION 2: guess ---
+    // Accepts a single letter guess, updates the round state, and reports
+    // what happened. Returns `Err` instead of mutating state when the
+    // guess can't be accepted.
+    pub fn guess(&mut self, c: char) -> Result<GuessOutcome, HangmanError> {
+        if self.is_over() {
+            return Err(HangmanError::GameOver);
+        }
+
+        let guessed_char = c.to_ascii_uppercase();
+        if !guessed_char.is_ascii_alphabetic() {
+            return Err(HangmanError::InvalidLetter);
+        }
+
+        if self.guessed_letters.contains(&guessed_char) {
+            return Err(HangmanError::AlreadyGuessed);
+        }
+
+        self.history.push(GameSnapshot {
+            hidden_word_display: self.hidden_word_display.clone(),
+            guessed_letters: self.guessed_letters.clone(),
+            wrong_guesses_count: self.wrong_guesses_count,
+        });
+
+        self.guessed_letters.push(guessed_char);
+        self.guessed_letters.sort_unstable();
+
+        let mut positions_revealed = 0;
+        for (i, &secret_char) in self.secret_word_chars.iter().enumerate() {
+            if secret_char == guessed_char {
+                self.hidden_word_display[i] = guessed_char;
+                positions_revealed += 1;
+            }
+        }
+
+        let hit = positions_revealed > 0;
+        if !hit {
+            self.wrong_guesses_count += 1;
+        }
+
+        Ok(GuessOutcome {
+            hit,
+            positions_revealed,
+            game_over: self.is_over(),
+        })
+    }
+
+    // --- FUNCTION 2b: undo ---
+    // Rolls back up to `n` guesses (fewer if that many haven't been made
+    // yet), restoring the display, guessed letters, and wrong-guess count
+    // exactly as they were beforehand. Returns how many guesses were undone.
+    pub fn undo(&mut self, n: usize) -> Result<usize, HangmanError> {
+        if n == 0 {
+            return Ok(0);
+        }
+        if self.history.is_empty() {
+            return Err(HangmanError::NothingToUndo);
+        }
+
+        let steps = n.min(self.history.len());
+        let snapshot = self.history[self.history.len() - steps].clone();
+        self.history.truncate(self.history.len() - steps);
+
+        self.hidden_word_display = snapshot.hidden_word_display;
+        self.guessed_letters = snapshot.guessed_letters;
+        self.wrong_guesses_count = snapshot.wrong_guesses_count;
+
+        Ok(steps)
+    }
+
+    // --- FUNCTION 3: hidden_word_display ---
+    // The secret word as currently revealed, with `_` for unguessed letters.
+    pub fn hidden_word_display(&self) -> &[char] {
+        &self.hidden_word_display
+    }
+
+    // --- FUNCTION 4: guessed_letters ---
+    // Every letter guessed so far, sorted alphabetically.
+    pub fn guessed_letters(&self) -> &[char] {
+        &self.guessed_letters
+    }
+
+    // --- FUNCTION 5: wrong_guesses_count ---
+    pub fn wrong_guesses_count(&self) -> u8 {
+        self.wrong_guesses_count
+    }
+
+    // --- FUNCTION 6: remaining_guesses ---
+    pub fn remaining_guesses(&self) -> u8 {
+        MAX_WRONG_GUESSES.saturating_sub(self.wrong_guesses_count)
+    }
+
+    // --- FUNCTION 7: secret_word ---
+    // The secret word (uppercased), for revealing it once the round ends.
+    pub fn secret_word(&self) -> String {
+        self.secret_word_chars.iter().collect()
+    }
+
+    // --- FUNCTION 8: is_won ---
+    pub fn is_won(&self) -> bool {
+        self.hidden_word_display.iter().all(|&c| c != '_')
+    }
+
+    // --- FUNCTION 9: is_over ---
+    // True once the round has been won or the wrong-guess limit is hit.
+    pub fn is_over(&self) -> bool {
+        self.wrong_guesses_count >= MAX_WRONG_GUESSES || self.is_won()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_zero_is_a_no_op() {
+        let mut game = Game::new("cat");
+        game.guess('c').unwrap();
+
+        assert_eq!(game.undo(0), Ok(0));
+        assert_eq!(game.hidden_word_display(), &['C', '_', '_']);
+        assert_eq!(game.guessed_letters(), &['C']);
+    }
+
+    #[test]
+    fn undo_zero_on_fresh_game_is_a_no_op() {
+        let mut game = Game::new("cat");
+        assert_eq!(game.undo(0), Ok(0));
+    }
+
+    #[test]
+    fn undo_restores_previous_state() {
+        let mut game = Game::new("cat");
+        game.guess('c').unwrap();
+        game.guess('z').unwrap();
+
+        assert_eq!(game.undo(1), Ok(1));
+        assert_eq!(game.hidden_word_display(), &['C', '_', '_']);
+        assert_eq!(game.guessed_letters(), &['C']);
+        assert_eq!(game.wrong_guesses_count(), 0);
+    }
+
+    #[test]
+    fn undo_more_than_history_clamps_to_history_len() {
+        let mut game = Game::new("cat");
+        game.guess('c').unwrap();
+        game.guess('a').unwrap();
+
+        assert_eq!(game.undo(100), Ok(2));
+        assert_eq!(game.hidden_word_display(), &['_', '_', '_']);
+        assert!(game.guessed_letters().is_empty());
+    }
+
+    #[test]
+    fn undo_with_no_history_is_an_error() {
+        let mut game = Game::new("cat");
+        assert_eq!(game.undo(1), Err(HangmanError::NothingToUndo));
+    }
+
+    #[test]
+    fn guess_reveals_hits_and_counts_misses() {
+        let mut game = Game::new("cat");
+
+        let hit = game.guess('c').unwrap();
+        assert!(hit.hit);
+        assert_eq!(hit.positions_revealed, 1);
+        assert_eq!(game.wrong_guesses_count(), 0);
+
+        let miss = game.guess('z').unwrap();
+        assert!(!miss.hit);
+        assert_eq!(miss.positions_revealed, 0);
+        assert_eq!(game.wrong_guesses_count(), 1);
+    }
+
+    #[test]
+    fn guess_rejects_non_letters_and_repeats() {
+        let mut game = Game::new("cat");
+        assert_eq!(game.guess('1'), Err(HangmanError::InvalidLetter));
+
+        game.guess('c').unwrap();
+        assert_eq!(game.guess('c'), Err(HangmanError::AlreadyGuessed));
+    }
+
+    #[test]
+    fn guessing_every_letter_wins_the_game() {
+        let mut game = Game::new("cat");
+        game.guess('c').unwrap();
+        game.guess('a').unwrap();
+        let outcome = game.guess('t').unwrap();
+
+        assert!(outcome.game_over);
+        assert!(game.is_won());
+        assert!(game.is_over());
+    }
+
+    #[test]
+    fn exhausting_wrong_guesses_loses_the_game() {
+        let mut game = Game::new("cat");
+        for letter in ['x', 'y', 'z', 'q', 'w', 'e'] {
+            game.guess(letter).unwrap();
+        }
+
+        assert!(!game.is_won());
+        assert!(game.is_over());
+        assert_eq!(game.guess('r'), Err(HangmanError::GameOver));
+    }
+}
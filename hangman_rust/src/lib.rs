@@ -0,0 +1,12 @@
+// --- HANGMAN CORE LIBRARY ---
+// The game engine, solver, and (optionally) the self-play bench harness,
+// kept free of any particular front end so the CLI binary, a WASM build,
+// or anything else can drive `Game` the same way.
+mod game;
+pub mod solver;
+pub mod wordlist;
+
+#[cfg(feature = "bench")]
+pub mod bench;
+
+pub use game::{Game, GuessOutcome, HangmanError, MAX_WRONG_GUESSES};
@@ -1,47 +1,126 @@
+use colored::Colorize; // For colorizing terminal output
 use rand::seq::SliceRandom; // For randomly selecting from a slice
 use rand::thread_rng; // For getting the thread-local random number generator
-use serde::Deserialize; // For deserializing JSON
-use std::fs; // For file system operations (reading file)
-use std::io::{self, Write}; // For standard input/output and flushing
-
-// --- STRUCT DEFINITIONS (Matching JSON structure) ---
-#[derive(Debug, Deserialize)]
-struct WordLists {
-    four_letter_words: Vec<String>,
-    five_letter_words: Vec<String>,
-    six_letter_words: Vec<String>,
+use std::collections::BTreeMap;
+use std::io::{self, IsTerminal, Write}; // For standard input/output, TTY detection, and flushing
+use std::path::PathBuf;
+
+use hangman_rust::solver::HangmanSolver;
+use hangman_rust::wordlist;
+use hangman_rust::{Game, HangmanError, MAX_WRONG_GUESSES};
+
+#[cfg(feature = "bench")]
+use hangman_rust::bench;
+
+// --- SOLVER MODE ---
+// Controls whether `play_hangman_round` asks the player for every letter,
+// offers a hint before each guess, or lets the `HangmanSolver` play the
+// whole round by itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SolverMode {
+    Off,
+    Hint,
+    Auto,
 }
 
-#[derive(Debug, Deserialize)]
-struct Root {
-    word_lists: WordLists,
+// --- FUNCTION 0: parse_solver_mode ---
+// Reads `--hint` / `--auto` from the command-line arguments.
+fn parse_solver_mode() -> SolverMode {
+    let args: Vec<String> = std::env::args().collect();
+    if args.iter().any(|arg| arg == "--auto") {
+        SolverMode::Auto
+    } else if args.iter().any(|arg| arg == "--hint") {
+        SolverMode::Hint
+    } else {
+        SolverMode::Off
+    }
+}
+
+// --- FUNCTION 0b: parse_bench_games ---
+// Reads `--bench <n>` from the command-line arguments, when the `bench`
+// feature is compiled in.
+#[cfg(feature = "bench")]
+fn parse_bench_games() -> Option<usize> {
+    let args: Vec<String> = std::env::args().collect();
+    let flag_index = args.iter().position(|arg| arg == "--bench")?;
+    args.get(flag_index + 1)?.parse().ok()
+}
+
+// --- FUNCTION 0d: parse_wordlist_path ---
+// Reads `--wordlist <path>` from the command-line arguments.
+fn parse_wordlist_path() -> Option<PathBuf> {
+    let args: Vec<String> = std::env::args().collect();
+    let flag_index = args.iter().position(|arg| arg == "--wordlist")?;
+    args.get(flag_index + 1).map(PathBuf::from)
+}
+
+// --- FUNCTION 0e: parse_length_arg ---
+// Reads `--length <n>` from the command-line arguments.
+fn parse_length_arg() -> Option<usize> {
+    let args: Vec<String> = std::env::args().collect();
+    let flag_index = args.iter().position(|arg| arg == "--length")?;
+    args.get(flag_index + 1)?.parse().ok()
+}
+
+// --- GALLOWS ART ---
+// One stage per wrong guess, from an empty scaffold up to the full figure
+// at `MAX_WRONG_GUESSES`. Sized off the shared constant so the two can
+// never drift out of sync.
+const GALLOWS_STAGES: [&str; (MAX_WRONG_GUESSES as usize) + 1] = [
+    "  +---+\n  |   |\n      |\n      |\n      |\n      |\n=========",
+    "  +---+\n  |   |\n  O   |\n      |\n      |\n      |\n=========",
+    "  +---+\n  |   |\n  O   |\n  |   |\n      |\n      |\n=========",
+    "  +---+\n  |   |\n  O   |\n /|   |\n      |\n      |\n=========",
+    "  +---+\n  |   |\n  O   |\n /|\\  |\n      |\n      |\n=========",
+    "  +---+\n  |   |\n  O   |\n /|\\  |\n /    |\n      |\n=========",
+    "  +---+\n  |   |\n  O   |\n /|\\  |\n / \\  |\n      |\n=========",
+];
+
+// --- FUNCTION 0c: colors_enabled ---
+// Only colorize output when stdout is an actual terminal, so piped or
+// redirected output stays plain.
+fn colors_enabled() -> bool {
+    io::stdout().is_terminal()
 }
 
-// --- GLOBAL CONSTANTS ---
-const MAX_WRONG_GUESSES: u8 = 6;
-const WORDS_JSON_PATH: &str = "hidden_words.json";
-
-// --- FUNCTION 1: load_words_from_json ---
-// Loads and parses word lists from a JSON file.
-// Returns a Result type: Ok(Root struct) on success, Err(Error type) on failure.
-fn load_words_from_json() -> Result<Root, Box<dyn std::error::Error>> {
-    println!("Attempting to load words from: {}", WORDS_JSON_PATH);
-    let json_content = fs::read_to_string(WORDS_JSON_PATH)?;
-    let root: Root = serde_json::from_str(&json_content)?;
-    println!("Words loaded successfully.");
-    Ok(root)
+// --- FUNCTION 1: load_word_list ---
+// Loads the dictionary to play from: `--wordlist <path>` if given (flat
+// text file or a JSON array of words), otherwise the builtin fallback list.
+fn load_word_list(path: Option<&PathBuf>) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    match path {
+        Some(path) => {
+            println!("Loading words from: {}", path.display());
+            let words = wordlist::load_from_file(path)?;
+            println!("Words loaded successfully.");
+            Ok(words)
+        }
+        None => {
+            println!("No --wordlist given; using the builtin word list.");
+            Ok(wordlist::builtin_words())
+        }
+    }
 }
 
 // --- FUNCTION 2: get_word_list_choice ---
-// Prompts the user to select a word list type (4, 5, or 6 letters).
-// Returns an Option<&Vec<String>>: Some(reference to list) on valid choice, None otherwise.
-fn get_word_list_choice(all_words: &Root) -> Option<&Vec<String>> {
+// Prompts the user to pick a word length from whatever lengths `buckets`
+// actually has words for, unless `fixed_length` (from `--length`) already
+// settled it. Returns an Option<&Vec<String>>: Some(reference to the
+// matching bucket) on valid choice, None if the user quit or if
+// `fixed_length` has no words in `buckets`.
+fn get_word_list_choice(
+    buckets: &BTreeMap<usize, Vec<String>>,
+    fixed_length: Option<usize>,
+) -> Option<&Vec<String>> {
+    if let Some(length) = fixed_length {
+        return buckets.get(&length);
+    }
+
+    let available_lengths: Vec<String> = buckets.keys().map(|len| len.to_string()).collect();
+
     loop {
-        println!("\nChoose a word list for Hangman:");
-        println!("1. 4-letter words");
-        println!("2. 5-letter words");
-        println!("3. 6-letter words");
-        println!("Enter your choice (1, 2, or 3, or 'q' to quit):");
+        println!("\nChoose a word length for Hangman:");
+        println!("Available lengths: {}", available_lengths.join(", "));
+        println!("Enter the desired length, or 'q' to quit:");
 
         let mut choice_input = String::new();
         io::stdin()
@@ -53,154 +132,255 @@ fn get_word_list_choice(all_words: &Root) -> Option<&Vec<String>> {
             return None; // User wants to quit
         }
 
-        match choice {
-            "1" => return Some(&all_words.word_lists.four_letter_words),
-            "2" => return Some(&all_words.word_lists.five_letter_words),
-            "3" => return Some(&all_words.word_lists.six_letter_words),
-            _ => {
-                println!("Invalid choice. Please enter 1, 2, 3, or 'q'.");
-                // Loop continues
-            }
+        match choice.parse::<usize>() {
+            Ok(length) if buckets.contains_key(&length) => return buckets.get(&length),
+            _ => println!("Invalid choice. Please enter one of: {}.", available_lengths.join(", ")),
         }
     }
 }
 
 // --- FUNCTION 3: select_random_word ---
 // Selects a random word from a given list.
-// Returns a reference to a String (the selected word). Panics if list is empty.
-fn select_random_word<'a>(word_list: &'a Vec<String>) -> &'a String {
+// Returns `None` instead of panicking if the list is empty.
+fn select_random_word(word_list: &[String]) -> Option<&String> {
     let mut rng = thread_rng();
-    word_list
-        .choose(&mut rng)
-        .expect("Word list is empty, cannot select a word.")
+    word_list.choose(&mut rng)
 }
 
 // --- FUNCTION 4: display_game_state (Helper for play_hangman_round) ---
-// Displays the current state of the game to the user.
+// Displays the current state of the game to the user: the gallows,
+// correctly/newly revealed letters in green, the most recent wrong guess
+// in red, and dimmed underscores for unrevealed letters. Colors are
+// skipped entirely when `colors_enabled` is false (e.g. piped output).
 fn display_game_state(
-    hidden_display_chars: &[char], // Using slice for efficiency
-    guessed_letters: &[char],     // Using slice for efficiency
-    remaining_guesses: u8,
+    game: &Game,
+    last_wrong_guess: Option<char>,
+    colors_enabled: bool,
 ) {
-    println!("\nWord: {}", hidden_display_chars.iter().collect::<String>());
-    println!(
-        "Guessed Letters: {}",
-        guessed_letters
-            .iter()
-            .map(|&c| c.to_string())
-            .collect::<Vec<String>>()
-            .join(", ")
-    );
-    println!("Guesses Left: {}", remaining_guesses);
+    println!("\n{}", GALLOWS_STAGES[game.wrong_guesses_count() as usize]);
+
+    let word_display: String = game
+        .hidden_word_display()
+        .iter()
+        .map(|&c| {
+            if !colors_enabled {
+                c.to_string()
+            } else if c == '_' {
+                c.to_string().dimmed().to_string()
+            } else {
+                c.to_string().green().to_string()
+            }
+        })
+        .collect();
+    println!("Word: {}", word_display);
+
+    let guessed_display: String = game
+        .guessed_letters()
+        .iter()
+        .map(|&c| {
+            if colors_enabled && Some(c) == last_wrong_guess {
+                c.to_string().red().to_string()
+            } else {
+                c.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+    println!("Guessed Letters: {}", guessed_display);
+
+    println!("Guesses Left: {}", game.remaining_guesses());
 }
 
-// --- FUNCTION 5: play_hangman_round ---
-// Contains the core game logic and user interaction for one round.
-// Returns BOOLEAN: TRUE if player wins, FALSE if player loses.
-fn play_hangman_round(secret_word_str: &str) -> bool {
-    // Convert secret word to uppercase characters for case-insensitive comparison
-    let secret_word_chars: Vec<char> = secret_word_str.to_uppercase().chars().collect();
-    let word_length = secret_word_chars.len();
+// --- ROUND OUTCOME ---
+// What the REPL decided to do once a round stopped running: start another
+// round, or leave the game entirely.
+enum RoundOutcome {
+    NewGame,
+    Quit,
+}
+
+// --- FUNCTION 4b: rebuild_solver ---
+// Recomputes a `HangmanSolver` from scratch against `game`'s current
+// state. Used after `undo`, since the solver's own candidate set can't be
+// rolled back independently of the game it's tracking.
+fn rebuild_solver(word_list: &[String], word_length: usize, game: &Game) -> HangmanSolver {
+    let mut solver = HangmanSolver::new(word_list, word_length);
+    for &letter in game.guessed_letters() {
+        let hit = game.hidden_word_display().contains(&letter);
+        solver.record_guess(letter, hit, game.hidden_word_display());
+    }
+    solver
+}
+
+// --- FUNCTION 4c: apply_guess ---
+// Shared by the `guess` REPL command and auto-play mode: submits a letter
+// to `game`, prints the result, and keeps the solver/highlight state in sync.
+fn apply_guess(
+    game: &mut Game,
+    guessed_char: char,
+    solver: &mut Option<HangmanSolver>,
+    last_wrong_guess: &mut Option<char>,
+) {
+    match game.guess(guessed_char) {
+        Ok(outcome) => {
+            let shown = guessed_char.to_ascii_uppercase();
+            if outcome.hit {
+                println!("Good guess! '{}' is in the word.", shown);
+            } else {
+                println!("'{}' is not in the word. You lose a guess.", shown);
+                *last_wrong_guess = Some(shown);
+            }
+            if let Some(solver) = solver {
+                solver.record_guess(shown, outcome.hit, game.hidden_word_display());
+            }
+        }
+        Err(HangmanError::InvalidLetter) => {
+            println!("Invalid input. Please enter a single alphabetic character.");
+        }
+        Err(HangmanError::AlreadyGuessed) => {
+            println!(
+                "You already guessed '{}'. Try a new letter.",
+                guessed_char.to_ascii_uppercase()
+            );
+        }
+        Err(HangmanError::GameOver) => {
+            println!("The round is already over. Try 'new' or 'quit'.");
+        }
+        Err(HangmanError::NothingToUndo) => unreachable!("guess() never returns NothingToUndo"),
+    }
+}
 
-    // Initialize the hidden word display with underscores
-    let mut hidden_word_display: Vec<char> = vec!['_'; word_length];
+// --- FUNCTION 5: play_hangman_round ---
+// Thin CLI driver over `hangman_rust::Game`: runs a small REPL supporting
+// `guess <letter>`, `undo [n]`, `new`, and `quit`, while the game struct
+// owns all of the actual round state (including the undo history).
+fn play_hangman_round(secret_word_str: &str, word_list: &[String], solver_mode: SolverMode) -> RoundOutcome {
+    let mut game = Game::new(secret_word_str);
+    let word_length = game.hidden_word_display().len();
+
+    let mut solver = match solver_mode {
+        SolverMode::Off => None,
+        SolverMode::Hint | SolverMode::Auto => Some(HangmanSolver::new(word_list, word_length)),
+    };
 
-    let mut guessed_letters: Vec<char> = Vec::new(); // Stores unique guessed letters
-    let mut wrong_guesses_count: u8 = 0;
+    let colors_enabled = colors_enabled();
+    let mut last_wrong_guess: Option<char> = None;
+    let mut announced_result = false;
 
     println!("\n--- Hangman Round Started! ---");
     println!("Your word has {} letters.", word_length);
+    println!("Commands: guess <letter> | undo [n] | new | quit");
 
     loop {
-        // Display current game state
-        display_game_state(
-            &hidden_word_display,
-            &guessed_letters,
-            MAX_WRONG_GUESSES - wrong_guesses_count,
-        );
-
-        // Check for game over (loss)
-        if wrong_guesses_count >= MAX_WRONG_GUESSES {
-            println!("\n--- GAME OVER! ---");
-            println!("You ran out of guesses. The word was: {}", secret_word_str);
-            return false; // Player lost
+        display_game_state(&game, last_wrong_guess, colors_enabled);
+
+        if game.is_over() && !announced_result {
+            if game.is_won() {
+                println!("\n--- CONGRATULATIONS! ---");
+                println!("You guessed the word: {}", secret_word_str);
+            } else {
+                println!("\n--- GAME OVER! ---");
+                println!("You ran out of guesses. The word was: {}", secret_word_str);
+            }
+            announced_result = true;
         }
 
-        // Check for win condition
-        if hidden_word_display.iter().all(|&c| c != '_') {
-            println!("\n--- CONGRATULATIONS! ---");
-            println!("You guessed the word: {}", secret_word_str);
-            return true; // Player won
+        if !game.is_over() {
+            if let (SolverMode::Hint, Some(solver)) = (solver_mode, &solver) {
+                match solver.best_guess(game.guessed_letters()) {
+                    Some(suggestion) => println!("Hint: try '{}'.", suggestion),
+                    None => println!("Hint: no candidates left, solver is out of ideas."),
+                }
+            }
+
+            if solver_mode == SolverMode::Auto {
+                let suggestion = solver
+                    .as_ref()
+                    .and_then(|solver| solver.best_guess(game.guessed_letters()));
+                match suggestion {
+                    Some(letter) => {
+                        println!("Auto-solver guesses: '{}'.", letter);
+                        apply_guess(&mut game, letter, &mut solver, &mut last_wrong_guess);
+                        continue;
+                    }
+                    None => {
+                        println!("Auto-solver has no candidates left; conceding the round.");
+                        println!("\n--- GAME OVER! ---");
+                        println!("You ran out of guesses. The word was: {}", secret_word_str);
+                        return RoundOutcome::NewGame;
+                    }
+                }
+            }
         }
 
-        // Prompt for guess
-        print!("Guess a letter: ");
-        io::stdout().flush().expect("Failed to flush stdout"); // Ensure prompt appears
+        print!("> ");
+        io::stdout().flush().expect("Failed to flush stdout");
 
-        let mut guess_input = String::new();
+        let mut command_input = String::new();
         io::stdin()
-            .read_line(&mut guess_input)
+            .read_line(&mut command_input)
             .expect("Failed to read line");
-        let guess_input = guess_input.trim(); // Remove newline and whitespace
-
-        // Validate guess input
-        if guess_input.len() != 1 {
-            println!("Invalid input. Please enter exactly one letter.");
-            continue; // Ask again
-        }
-
-        let guessed_char = guess_input
-            .chars()
-            .next()
-            .unwrap()
-            .to_ascii_uppercase(); // Get char and convert to uppercase
-
-        if !guessed_char.is_ascii_alphabetic() {
-            println!("Invalid input. Please enter an alphabetic character.");
-            continue;
-        }
-
-        // Check if letter was already guessed
-        if guessed_letters.contains(&guessed_char) {
-            println!("You already guessed '{}'. Try a new letter.", guessed_char);
-            continue;
-        }
-
-        // Add the new guess to the list of guessed letters
-        guessed_letters.push(guessed_char);
-        guessed_letters.sort_unstable(); // Keep list sorted for better display
-
-        // Compare with secret word and update display (handling duplicates)
-        let mut found_in_word = false;
-        for (i, &secret_char) in secret_word_chars.iter().enumerate() {
-            if secret_char == guessed_char {
-                hidden_word_display[i] = guessed_char;
-                found_in_word = true;
+        let mut tokens = command_input.split_whitespace();
+
+        match tokens.next() {
+            Some("quit") => return RoundOutcome::Quit,
+            Some("new") => return RoundOutcome::NewGame,
+            Some("undo") => {
+                let steps = tokens.next().and_then(|arg| arg.parse().ok()).unwrap_or(1);
+                match game.undo(steps) {
+                    Ok(undone) => {
+                        println!("Undid {} guess(es).", undone);
+                        last_wrong_guess = None;
+                        announced_result = false;
+                        if solver_mode != SolverMode::Off {
+                            solver = Some(rebuild_solver(word_list, word_length, &game));
+                        }
+                    }
+                    Err(HangmanError::NothingToUndo) => println!("Nothing to undo."),
+                    Err(_) => unreachable!("undo() only returns NothingToUndo"),
+                }
             }
-        }
-
-        // Handle correct/incorrect guess
-        if found_in_word {
-            println!("Good guess! '{}' is in the word.", guessed_char);
-        } else {
-            println!("'{}' is not in the word. You lose a guess.", guessed_char);
-            wrong_guesses_count += 1;
+            Some("guess") => {
+                if game.is_over() {
+                    println!("The round is over. Try 'new' or 'quit'.");
+                } else {
+                    match tokens.next().and_then(|arg| arg.chars().next()) {
+                        Some(letter) => apply_guess(&mut game, letter, &mut solver, &mut last_wrong_guess),
+                        None => println!("Usage: guess <letter>"),
+                    }
+                }
+            }
+            Some(other) => println!("Unknown command '{}'. Try: guess/undo/new/quit.", other),
+            None => {} // Blank line: just redraw the board.
         }
     }
 }
 
 // --- MAIN PROGRAM FLOW ---
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let all_words = match load_words_from_json() {
+    let solver_mode = parse_solver_mode();
+    let wordlist_path = parse_wordlist_path();
+    let fixed_length = parse_length_arg();
+
+    let all_words = match load_word_list(wordlist_path.as_ref()) {
         Ok(words) => words,
         Err(e) => {
             eprintln!("Failed to start game due to data loading error: {}", e);
             return Err(e); // Exit program with error
         }
     };
+    let buckets = wordlist::bucket_by_length(&all_words);
+
+    #[cfg(feature = "bench")]
+    if let Some(games) = parse_bench_games() {
+        let selected_word_list = get_word_list_choice(&buckets, fixed_length)
+            .ok_or("Benchmark requires a word length with available words")?;
+        bench::run_benchmark(selected_word_list, games).print_table();
+        return Ok(());
+    }
 
     loop {
-        let selected_list_option = get_word_list_choice(&all_words);
+        let selected_list_option = get_word_list_choice(&buckets, fixed_length);
 
         let selected_word_list = match selected_list_option {
             Some(list) => list,
@@ -211,28 +391,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         };
 
-        if selected_word_list.is_empty() {
-            println!("The selected word list is empty. Please check your JSON file.");
-            continue; // Ask for choice again
-        }
-
-        let secret_word = select_random_word(selected_word_list);
-
-        // Play the actual game round
-        let _player_won = play_hangman_round(secret_word); // We don't strictly need to use `_player_won` here
-
-        // Ask if user wants to play another round
-        println!("\nPlay another round? (yes/no)");
-        print!("> "); // Simple prompt for consistency
-        io::stdout().flush()?;
+        let secret_word = match select_random_word(selected_word_list) {
+            Some(word) => word,
+            None => {
+                println!("The selected word list is empty. Please check your word list.");
+                continue; // Ask for choice again
+            }
+        };
 
-        let mut play_again_input = String::new();
-        io::stdin().read_line(&mut play_again_input)?;
-        if !play_again_input.trim().eq_ignore_ascii_case("yes") {
-            println!("Thanks for playing!");
-            break; // Exit the main game loop
+        // Play the actual game round; the in-round REPL's `new`/`quit`
+        // commands decide what happens next.
+        match play_hangman_round(secret_word, selected_word_list, solver_mode) {
+            RoundOutcome::NewGame => continue,
+            RoundOutcome::Quit => {
+                println!("Thanks for playing!");
+                break; // Exit the main game loop
+            }
         }
     }
 
     Ok(())
-}
\ No newline at end of file
+}
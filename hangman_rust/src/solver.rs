@@ -0,0 +1,158 @@
+// --- HANGMAN SOLVER ---
+// A small helper that suggests (or automatically plays) the statistically
+// best next letter, similar in spirit to the solver modules found in the
+// Wordle-style crates: keep a live set of candidate words consistent with
+// everything revealed so far, and recommend whichever unguessed letter
+// appears in the most candidates.
+
+// --- STRUCT DEFINITION ---
+// Holds the shrinking set of words that are still consistent with the
+// guesses made so far.
+pub struct HangmanSolver {
+    candidates: Vec<String>,
+}
+
+impl HangmanSolver {
+    // --- FUNCTION 1: new ---
+    // Builds a solver starting from every word in `word_list` that matches
+    // the secret word's length.
+    pub fn new(word_list: &[String], word_length: usize) -> Self {
+        let candidates = word_list
+            .iter()
+            .filter(|word| word.chars().count() == word_length)
+            .map(|word| word.to_uppercase())
+            .collect();
+
+        HangmanSolver { candidates }
+    }
+
+    // --- FUNCTION 2: best_guess ---
+    // Counts how many remaining candidates contain each unguessed letter,
+    // then returns the most frequent one (ties broken alphabetically).
+    pub fn best_guess(&self, guessed_letters: &[char]) -> Option<char> {
+        let mut letter_counts: [usize; 26] = [0; 26];
+
+        for word in &self.candidates {
+            let mut seen_in_word = [false; 26];
+            for c in word.chars() {
+                if let Some(index) = letter_index(c) {
+                    if !seen_in_word[index] {
+                        letter_counts[index] += 1;
+                        seen_in_word[index] = true;
+                    }
+                }
+            }
+        }
+
+        (0..26)
+            .map(|index| ((b'A' + index as u8) as char, letter_counts[index]))
+            .filter(|(letter, _)| !guessed_letters.contains(letter))
+            .filter(|(_, count)| *count > 0)
+            .max_by_key(|(letter, count)| (*count, std::cmp::Reverse(*letter)))
+            .map(|(letter, _)| letter)
+    }
+
+    // --- FUNCTION 3: record_guess ---
+    // Narrows the candidate set after `play_hangman_round` reveals whether
+    // `letter` was found, and if so, where it landed in `hidden_word_display`.
+    pub fn record_guess(&mut self, letter: char, found: bool, hidden_word_display: &[char]) {
+        if found {
+            self.candidates.retain(|word| {
+                let word_chars: Vec<char> = word.chars().collect();
+                word_chars
+                    .iter()
+                    .zip(hidden_word_display.iter())
+                    .all(|(&word_char, &revealed_char)| {
+                        (revealed_char == '_' && word_char != letter) || revealed_char == word_char
+                    })
+                    && word_chars.contains(&letter)
+            });
+        } else {
+            self.candidates.retain(|word| !word.contains(letter));
+        }
+    }
+
+    // --- FUNCTION 4: candidate_count ---
+    // Exposes how many words are still consistent with the guesses so far,
+    // mostly useful for diagnostics and the bench harness.
+    pub fn candidate_count(&self) -> usize {
+        self.candidates.len()
+    }
+}
+
+// --- FUNCTION 5: letter_index ---
+// Maps an uppercase ASCII letter to a 0..26 index for the frequency table.
+fn letter_index(c: char) -> Option<usize> {
+    if c.is_ascii_uppercase() {
+        Some((c as u8 - b'A') as usize)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word_list() -> Vec<String> {
+        ["cat", "car", "can", "dog"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn new_filters_candidates_by_word_length() {
+        let solver = HangmanSolver::new(&word_list(), 3);
+        assert_eq!(solver.candidate_count(), 4);
+
+        let solver = HangmanSolver::new(&word_list(), 5);
+        assert_eq!(solver.candidate_count(), 0);
+    }
+
+    #[test]
+    fn best_guess_picks_the_most_common_unguessed_letter() {
+        let solver = HangmanSolver::new(&word_list(), 3);
+        // 'A' and 'C' both appear in cat/car/can (3 of 4 candidates), tied for
+        // the most of any letter; ties break alphabetically.
+        assert_eq!(solver.best_guess(&[]), Some('A'));
+        assert_eq!(solver.best_guess(&['A']), Some('C'));
+    }
+
+    #[test]
+    fn best_guess_ignores_already_guessed_letters() {
+        let solver = HangmanSolver::new(&word_list(), 3);
+        // With 'A' and 'C' guessed, every remaining letter appears in exactly
+        // one candidate, so the alphabetically-first one wins.
+        assert_eq!(solver.best_guess(&['A', 'C']), Some('D'));
+    }
+
+    #[test]
+    fn best_guess_returns_none_with_no_candidates() {
+        let solver = HangmanSolver::new(&word_list(), 5);
+        assert_eq!(solver.best_guess(&[]), None);
+    }
+
+    #[test]
+    fn record_guess_hit_narrows_to_matching_words() {
+        let mut solver = HangmanSolver::new(&word_list(), 3);
+        solver.record_guess('C', true, &['C', '_', '_']);
+        assert_eq!(solver.candidate_count(), 3);
+
+        solver.record_guess('A', true, &['C', 'A', '_']);
+        assert_eq!(solver.candidate_count(), 3);
+
+        solver.record_guess('T', true, &['C', 'A', 'T']);
+        assert_eq!(solver.candidate_count(), 1);
+    }
+
+    #[test]
+    fn record_guess_miss_drops_words_containing_the_letter() {
+        let mut solver = HangmanSolver::new(&word_list(), 3);
+        solver.record_guess('D', false, &['_', '_', '_']);
+        assert_eq!(solver.candidate_count(), 3);
+
+        solver.record_guess('R', false, &['_', '_', '_']);
+        assert_eq!(solver.candidate_count(), 2);
+    }
+}
@@ -0,0 +1,179 @@
+// --- WORD LIST LOADING ---
+// Generalizes word-list loading away from the old hardcoded
+// four/five/six-letter JSON schema: any flat dictionary file (one word
+// per line) or a JSON array of words can be loaded and bucketed by
+// length on the fly, mirroring the builtin-wordlist approach used by the
+// Wordle-style crates.
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+// --- BUILTIN FALLBACK LIST ---
+// A small built-in English word list so the game works with no
+// `--wordlist` argument at all.
+pub const BUILTIN_WORDS: &[&str] = &[
+    "cat", "dog", "sun", "sky", "run", "jump", "frog", "lion", "bear", "wolf", "tree", "book",
+    "house", "mouse", "plant", "bread", "chair", "stone", "river", "horse", "castle", "garden",
+    "bridge", "planet", "window", "rocket", "hunter", "puzzle", "dragon", "knight", "feather",
+    "journey", "mystery", "glacier", "whisper", "harvest",
+];
+
+// --- ENUM DEFINITION ---
+// Everything that can go wrong while loading a word list.
+#[derive(Debug)]
+pub enum WordlistError {
+    /// The file couldn't be read from disk.
+    Io(std::io::Error),
+    /// The file looked like JSON but didn't parse as an array of strings.
+    Json(serde_json::Error),
+    /// The file produced no words at all.
+    Empty,
+}
+
+impl fmt::Display for WordlistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordlistError::Io(e) => write!(f, "failed to read word list: {}", e),
+            WordlistError::Json(e) => write!(f, "failed to parse word list as JSON: {}", e),
+            WordlistError::Empty => write!(f, "word list is empty"),
+        }
+    }
+}
+
+impl std::error::Error for WordlistError {}
+
+impl From<std::io::Error> for WordlistError {
+    fn from(e: std::io::Error) -> Self {
+        WordlistError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for WordlistError {
+    fn from(e: serde_json::Error) -> Self {
+        WordlistError::Json(e)
+    }
+}
+
+// --- FUNCTION 1: builtin_words ---
+// The fallback word list, owned and ready to bucket.
+pub fn builtin_words() -> Vec<String> {
+    BUILTIN_WORDS.iter().map(|w| w.to_string()).collect()
+}
+
+// --- FUNCTION 2: load_from_file ---
+// Loads words from `path`. Files ending in `.json` are parsed as a flat
+// JSON array of strings (`["alpha", "beta", ...]`); anything else is
+// treated as a plain dictionary file with one word per line.
+pub fn load_from_file(path: &Path) -> Result<Vec<String>, WordlistError> {
+    let contents = fs::read_to_string(path)?;
+
+    let words = if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+        serde_json::from_str::<Vec<String>>(&contents)?
+    } else {
+        contents
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| line.to_string())
+            .collect()
+    };
+
+    if words.is_empty() {
+        return Err(WordlistError::Empty);
+    }
+
+    Ok(words)
+}
+
+// --- FUNCTION 3: bucket_by_length ---
+// Groups words by their character length, so the game can offer whatever
+// lengths the loaded list actually supports instead of a hardcoded 4-6.
+pub fn bucket_by_length(words: &[String]) -> BTreeMap<usize, Vec<String>> {
+    let mut buckets: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+    for word in words {
+        buckets
+            .entry(word.chars().count())
+            .or_default()
+            .push(word.clone());
+    }
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    // Each test gets its own path under the system temp dir so tests can
+    // run concurrently without clobbering each other's files.
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!("hangman_rust_wordlist_test_{unique}_{name}"))
+    }
+
+    #[test]
+    fn load_from_file_reads_plain_text_lines() {
+        let path = temp_path("words.txt");
+        fs::write(&path, "cat\ndog\n\nbear\n").unwrap();
+
+        let words = load_from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(words, vec!["cat", "dog", "bear"]);
+    }
+
+    #[test]
+    fn load_from_file_reads_json_array() {
+        let path = temp_path("words.json");
+        fs::write(&path, r#"["cat", "dog", "bear"]"#).unwrap();
+
+        let words = load_from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(words, vec!["cat", "dog", "bear"]);
+    }
+
+    #[test]
+    fn load_from_file_rejects_malformed_json() {
+        let path = temp_path("bad.json");
+        fs::write(&path, "not json").unwrap();
+
+        let result = load_from_file(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(WordlistError::Json(_))));
+    }
+
+    #[test]
+    fn load_from_file_rejects_empty_list() {
+        let path = temp_path("empty.txt");
+        fs::write(&path, "\n\n").unwrap();
+
+        let result = load_from_file(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(WordlistError::Empty)));
+    }
+
+    #[test]
+    fn load_from_file_reports_io_errors() {
+        let path = temp_path("does_not_exist.txt");
+        assert!(matches!(load_from_file(&path), Err(WordlistError::Io(_))));
+    }
+
+    #[test]
+    fn bucket_by_length_groups_words_by_char_count() {
+        let words: Vec<String> = ["cat", "dog", "lion", "frog"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+
+        let buckets = bucket_by_length(&words);
+
+        assert_eq!(buckets[&3], vec!["cat".to_string(), "dog".to_string()]);
+        assert_eq!(buckets[&4], vec!["lion".to_string(), "frog".to_string()]);
+        assert_eq!(buckets.len(), 2);
+    }
+}